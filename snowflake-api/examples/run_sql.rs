@@ -6,7 +6,7 @@ use clap::{ArgAction, Parser};
 use futures_util::StreamExt;
 use std::fs;
 
-use snowflake_api::{responses::ExecResponse, QueryResult, RawQueryResult, SnowflakeApi};
+use snowflake_api::{QueryResult, SnowflakeApi};
 
 #[derive(clap::ValueEnum, Clone, Debug)]
 enum Output {
@@ -26,6 +26,10 @@ struct Args {
     #[arg(long)]
     password: Option<String>,
 
+    /// Passphrase for an encrypted private key
+    #[arg(long)]
+    key_passphrase: Option<String>,
+
     /// <account_identifier> in Snowflake format, uppercase
     #[arg(short, long)]
     account_identifier: String,
@@ -63,6 +67,12 @@ struct Args {
 
     #[clap(long, action = ArgAction::Set)]
     stream: bool,
+
+    /// Positional binding for a `?` placeholder in `--sql`, repeatable in order.
+    /// Only applied to a single statement with `--output arrow` and no `--stream`;
+    /// ignored otherwise
+    #[arg(long = "bind", action = ArgAction::Append)]
+    bind: Vec<String>,
 }
 
 #[tokio::main]
@@ -74,15 +84,27 @@ async fn main() -> Result<()> {
     let mut api = match (&args.private_key, &args.password) {
         (Some(pkey), None) => {
             let pem = fs::read_to_string(pkey)?;
-            SnowflakeApi::with_certificate_auth(
-                &args.account_identifier,
-                args.warehouse.as_deref(),
-                args.database.as_deref(),
-                args.schema.as_deref(),
-                &args.username,
-                args.role.as_deref(),
-                &pem,
-            )?
+            match &args.key_passphrase {
+                Some(passphrase) => SnowflakeApi::with_certificate_auth_encrypted(
+                    &args.account_identifier,
+                    args.warehouse.as_deref(),
+                    args.database.as_deref(),
+                    args.schema.as_deref(),
+                    &args.username,
+                    args.role.as_deref(),
+                    &pem,
+                    passphrase,
+                )?,
+                None => SnowflakeApi::with_certificate_auth(
+                    &args.account_identifier,
+                    args.warehouse.as_deref(),
+                    args.database.as_deref(),
+                    args.schema.as_deref(),
+                    &args.username,
+                    args.role.as_deref(),
+                    &pem,
+                )?,
+            }
         }
         (None, Some(pwd)) => SnowflakeApi::with_password_auth(
             &args.account_identifier,
@@ -101,36 +123,50 @@ async fn main() -> Result<()> {
     .with_host(args.host);
 
     if args.stream {
-        let resp = api.exec_raw(&args.sql, true).await?;
-
-        if let RawQueryResult::Stream(mut bytes_stream) = resp {
-            let mut chunks = vec![];
-            while let Some(bytes) = bytes_stream.next().await {
-                chunks.push(bytes?);
-            }
-
-            let bytes = chunks.into_iter().flatten().collect::<Vec<u8>>();
-            let resp = serde_json::from_slice::<ExecResponse>(&bytes).unwrap();
-            let raw_query_result = api.parse_arrow_raw_response(resp).await.unwrap();
-            let batches = raw_query_result.deserialize_arrow().unwrap();
-
-            if let QueryResult::Arrow(a) = batches {
-                println!("{}", pretty_format_batches(&a).unwrap());
+        let streamed = api.exec_streamed(&args.sql).await?;
+        println!("fetching {} partition(s)", streamed.partition_count());
+
+        let mut partitions = Box::pin(streamed.partitions());
+        while let Some(result) = partitions.next().await {
+            match result? {
+                QueryResult::Arrow(a) => println!("{}", pretty_format_batches(&a)?),
+                QueryResult::Json(rows) => println!("{}", serde_json::Value::Array(rows)),
+                QueryResult::Empty => println!("Query finished successfully"),
             }
         }
     } else {
         match args.output {
             Output::Arrow => {
-                let res = api.exec(&args.sql).await?;
-                match res {
-                    QueryResult::Arrow(a) => {
-                        println!("{}", pretty_format_batches(&a).unwrap());
+                let statements: Vec<&str> =
+                    args.sql.split(';').map(str::trim).filter(|s| !s.is_empty()).collect();
+
+                let results = if statements.len() > 1 {
+                    api.exec_batch(&statements).await?
+                } else if args.bind.is_empty() {
+                    vec![api.exec(&args.sql).await?]
+                } else {
+                    let mut stmt = api.prepare(&args.sql);
+                    for bind in &args.bind {
+                        stmt = stmt.add_binding(bind.clone());
                     }
-                    QueryResult::Json(j) => {
-                        println!("{j}");
+                    vec![stmt.exec().await?]
+                };
+
+                let multiple = results.len() > 1;
+                for (i, res) in results.into_iter().enumerate() {
+                    if multiple {
+                        println!("-- statement {} --", i + 1);
                     }
-                    QueryResult::Empty => {
-                        println!("Query finished successfully")
+                    match res {
+                        QueryResult::Arrow(a) => {
+                            println!("{}", pretty_format_batches(&a).unwrap());
+                        }
+                        QueryResult::Json(rows) => {
+                            println!("{}", serde_json::Value::Array(rows));
+                        }
+                        QueryResult::Empty => {
+                            println!("Query finished successfully")
+                        }
                     }
                 }
             }
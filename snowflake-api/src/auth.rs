@@ -0,0 +1,101 @@
+use base64::engine::general_purpose::STANDARD as base64;
+use base64::Engine;
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey};
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::{BigUint, RsaPrivateKey};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::error::{Result, SnowflakeApiError};
+
+#[derive(Serialize)]
+struct Claims {
+    iss: String,
+    sub: String,
+    iat: i64,
+    exp: i64,
+}
+
+/// Builds the RS256 JWT that Snowflake expects for key-pair authentication.
+///
+/// The issuer/subject encode the account and user together with a SHA-256
+/// fingerprint of the public key, per Snowflake's key-pair auth spec.
+/// `private_key_pem` may be a plaintext PKCS#8 or PKCS#1 key, a
+/// passphrase-encrypted PKCS#8 key, or an OpenSSH-format (optionally
+/// encrypted) RSA key.
+pub fn build_jwt(
+    account_identifier: &str,
+    username: &str,
+    private_key_pem: &str,
+    passphrase: Option<&str>,
+) -> Result<String> {
+    let private_key = load_rsa_private_key(private_key_pem, passphrase)?;
+
+    let public_key_der = private_key
+        .to_public_key()
+        .to_public_key_der()
+        .map_err(|e| SnowflakeApiError::AuthError(e.to_string()))?;
+    let fingerprint = base64.encode(Sha256::digest(public_key_der.as_bytes()));
+
+    let account = account_identifier.to_uppercase();
+    let user = username.to_uppercase();
+    let qualified_username = format!("{account}.{user}");
+
+    let now = Utc::now();
+    let claims = Claims {
+        iss: format!("{qualified_username}.SHA256:{fingerprint}"),
+        sub: qualified_username,
+        iat: now.timestamp(),
+        exp: (now + Duration::hours(1)).timestamp(),
+    };
+
+    let pkcs8_pem = private_key
+        .to_pkcs8_pem(pkcs8::LineEnding::LF)
+        .map_err(|e| SnowflakeApiError::AuthError(e.to_string()))?;
+    let encoding_key = EncodingKey::from_rsa_pem(pkcs8_pem.as_bytes())?;
+
+    Ok(encode(&Header::new(Algorithm::RS256), &claims, &encoding_key)?)
+}
+
+/// Parses an RSA private key out of a plaintext PKCS#8 or PKCS#1 PEM, a
+/// passphrase-encrypted PKCS#8 PEM, or an OpenSSH-format key (also
+/// optionally passphrase-protected).
+fn load_rsa_private_key(pem: &str, passphrase: Option<&str>) -> Result<RsaPrivateKey> {
+    if pem.contains("OPENSSH PRIVATE KEY") {
+        let key = ssh_key::PrivateKey::from_openssh(pem).map_err(|e| SnowflakeApiError::AuthError(e.to_string()))?;
+        let key = match passphrase {
+            Some(p) if key.is_encrypted() => {
+                key.decrypt(p).map_err(|e| SnowflakeApiError::AuthError(e.to_string()))?
+            }
+            _ => key,
+        };
+
+        let ssh_key::private::KeypairData::Rsa(keypair) = key.key_data() else {
+            return Err(SnowflakeApiError::AuthError("only RSA SSH keys are supported".into()));
+        };
+
+        rsa_from_ssh_keypair(keypair)
+    } else if let Some(passphrase) = passphrase {
+        RsaPrivateKey::from_pkcs8_encrypted_pem(pem, passphrase).map_err(|e| SnowflakeApiError::AuthError(e.to_string()))
+    } else {
+        // Plaintext keys come in either PKCS#8 (`BEGIN PRIVATE KEY`) or the
+        // older PKCS#1 (`BEGIN RSA PRIVATE KEY`) form; try both.
+        RsaPrivateKey::from_pkcs8_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem))
+            .map_err(|e| SnowflakeApiError::AuthError(e.to_string()))
+    }
+}
+
+fn rsa_from_ssh_keypair(keypair: &ssh_key::private::RsaKeypair) -> Result<RsaPrivateKey> {
+    let mpint = |m: &ssh_key::Mpint| BigUint::from_bytes_be(m.as_positive_bytes().unwrap_or_default());
+
+    RsaPrivateKey::from_components(
+        mpint(&keypair.public.n),
+        mpint(&keypair.public.e),
+        mpint(&keypair.private.d),
+        vec![mpint(&keypair.private.p), mpint(&keypair.private.q)],
+    )
+    .map_err(|e| SnowflakeApiError::AuthError(e.to_string()))
+}
@@ -0,0 +1,134 @@
+use serde::Serialize;
+
+/// JSON-serializable representation of a single bound value, in the shape
+/// Snowflake's SQL API expects: `{"type": "<SNOWFLAKE_TYPE>", "value": "<stringified>"}`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Binding {
+    #[serde(rename = "type")]
+    pub(crate) type_tag: &'static str,
+    pub(crate) value: Option<String>,
+}
+
+/// Maps a Rust value onto the Snowflake binding it should be sent as.
+/// Implemented for the common scalar types plus `Option<T>` for NULL.
+pub trait ToBinding {
+    fn to_binding(&self) -> Binding;
+}
+
+macro_rules! impl_to_binding {
+    ($ty:ty, $tag:literal) => {
+        impl ToBinding for $ty {
+            fn to_binding(&self) -> Binding {
+                Binding {
+                    type_tag: $tag,
+                    value: Some(self.to_string()),
+                }
+            }
+        }
+    };
+}
+
+impl_to_binding!(i8, "FIXED");
+impl_to_binding!(i16, "FIXED");
+impl_to_binding!(i32, "FIXED");
+impl_to_binding!(i64, "FIXED");
+impl_to_binding!(u32, "FIXED");
+impl_to_binding!(u64, "FIXED");
+impl_to_binding!(f32, "REAL");
+impl_to_binding!(f64, "REAL");
+impl_to_binding!(bool, "BOOLEAN");
+
+impl ToBinding for str {
+    fn to_binding(&self) -> Binding {
+        Binding {
+            type_tag: "TEXT",
+            value: Some(self.to_string()),
+        }
+    }
+}
+
+impl ToBinding for String {
+    fn to_binding(&self) -> Binding {
+        self.as_str().to_binding()
+    }
+}
+
+impl<T: ToBinding> ToBinding for Option<T> {
+    fn to_binding(&self) -> Binding {
+        match self {
+            Some(inner) => inner.to_binding(),
+            None => Binding {
+                type_tag: "TEXT",
+                value: None,
+            },
+        }
+    }
+}
+
+impl ToBinding for &str {
+    fn to_binding(&self) -> Binding {
+        (*self).to_binding()
+    }
+}
+
+impl<T: ToBinding> ToBinding for &T {
+    fn to_binding(&self) -> Binding {
+        (**self).to_binding()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_bind_as_fixed() {
+        let binding = 42i64.to_binding();
+        assert_eq!(binding.type_tag, "FIXED");
+        assert_eq!(binding.value.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn floats_bind_as_real() {
+        let binding = 3.5f64.to_binding();
+        assert_eq!(binding.type_tag, "REAL");
+        assert_eq!(binding.value.as_deref(), Some("3.5"));
+    }
+
+    #[test]
+    fn bools_bind_as_boolean() {
+        let binding = true.to_binding();
+        assert_eq!(binding.type_tag, "BOOLEAN");
+        assert_eq!(binding.value.as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn strings_bind_as_text() {
+        assert_eq!("hello".to_binding().type_tag, "TEXT");
+        assert_eq!("hello".to_binding().value.as_deref(), Some("hello"));
+        assert_eq!(String::from("hello").to_binding().value.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn some_binds_as_the_inner_value() {
+        let binding = Some(7i32).to_binding();
+        assert_eq!(binding.type_tag, "FIXED");
+        assert_eq!(binding.value.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn none_binds_as_null() {
+        let binding: Binding = None::<i32>.to_binding();
+        assert_eq!(binding.type_tag, "TEXT");
+        assert_eq!(binding.value, None);
+    }
+
+    #[test]
+    fn references_delegate_to_the_inner_impl() {
+        fn bind_ref<T: ToBinding>(value: &T) -> Binding {
+            value.to_binding()
+        }
+
+        assert_eq!(bind_ref(&5i32).value.as_deref(), Some("5"));
+    }
+}
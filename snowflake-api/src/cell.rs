@@ -0,0 +1,165 @@
+use arrow::array::Array;
+use serde::Serialize;
+
+/// A single cell's value, normalized across Arrow- and JSON-encoded result
+/// formats so callers don't need the `arrow` crate to read out individual
+/// values.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Cell {
+    Int(i64),
+    Float(f64),
+    Varchar(String),
+    Boolean(bool),
+    Timestamp(String),
+    Null,
+}
+
+/// Converts a `Cell` into a bare JSON value (an `Int` into a `Number`, a
+/// `Null` into JSON `null`, etc.) rather than the externally-tagged form
+/// `#[derive(Serialize)]` would otherwise produce (e.g. `{"Int": 7}`).
+impl From<Cell> for serde_json::Value {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Int(n) => serde_json::Value::Number(n.into()),
+            Cell::Float(f) => serde_json::Number::from_f64(f).map(serde_json::Value::Number).unwrap_or(serde_json::Value::Null),
+            Cell::Varchar(s) => serde_json::Value::String(s),
+            Cell::Boolean(b) => serde_json::Value::Bool(b),
+            Cell::Timestamp(s) => serde_json::Value::String(s),
+            Cell::Null => serde_json::Value::Null,
+        }
+    }
+}
+
+impl Cell {
+    pub(crate) fn from_json(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Cell::Null,
+            serde_json::Value::Bool(b) => Cell::Boolean(*b),
+            serde_json::Value::Number(n) => n
+                .as_i64()
+                .map(Cell::Int)
+                .unwrap_or_else(|| Cell::Float(n.as_f64().unwrap_or_default())),
+            serde_json::Value::String(s) => Cell::Varchar(s.clone()),
+            other => Cell::Varchar(other.to_string()),
+        }
+    }
+
+    pub(crate) fn from_arrow(column: &dyn Array, row: usize) -> Self {
+        use arrow::array::{
+            BooleanArray, Date32Array, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array,
+            StringArray, TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+            TimestampSecondArray,
+        };
+        use arrow::datatypes::{DataType, TimeUnit};
+
+        if column.is_null(row) {
+            return Cell::Null;
+        }
+
+        match column.data_type() {
+            DataType::Int8 => Cell::Int(column.as_any().downcast_ref::<Int8Array>().unwrap().value(row) as i64),
+            DataType::Int16 => Cell::Int(column.as_any().downcast_ref::<Int16Array>().unwrap().value(row) as i64),
+            DataType::Int32 => Cell::Int(column.as_any().downcast_ref::<Int32Array>().unwrap().value(row) as i64),
+            DataType::Int64 => Cell::Int(column.as_any().downcast_ref::<Int64Array>().unwrap().value(row)),
+            DataType::Float32 => {
+                Cell::Float(column.as_any().downcast_ref::<Float32Array>().unwrap().value(row) as f64)
+            }
+            DataType::Float64 => Cell::Float(column.as_any().downcast_ref::<Float64Array>().unwrap().value(row)),
+            DataType::Boolean => Cell::Boolean(column.as_any().downcast_ref::<BooleanArray>().unwrap().value(row)),
+            DataType::Utf8 => {
+                Cell::Varchar(column.as_any().downcast_ref::<StringArray>().unwrap().value(row).to_string())
+            }
+            DataType::Date32 => Cell::Timestamp(
+                column
+                    .as_any()
+                    .downcast_ref::<Date32Array>()
+                    .unwrap()
+                    .value_as_date(row)
+                    .map(|d| d.to_string())
+                    .unwrap_or_default(),
+            ),
+            DataType::Timestamp(unit, _) => Cell::Timestamp(
+                match unit {
+                    TimeUnit::Second => column
+                        .as_any()
+                        .downcast_ref::<TimestampSecondArray>()
+                        .and_then(|a| a.value_as_datetime(row)),
+                    TimeUnit::Millisecond => column
+                        .as_any()
+                        .downcast_ref::<TimestampMillisecondArray>()
+                        .and_then(|a| a.value_as_datetime(row)),
+                    TimeUnit::Microsecond => column
+                        .as_any()
+                        .downcast_ref::<TimestampMicrosecondArray>()
+                        .and_then(|a| a.value_as_datetime(row)),
+                    TimeUnit::Nanosecond => column
+                        .as_any()
+                        .downcast_ref::<TimestampNanosecondArray>()
+                        .and_then(|a| a.value_as_datetime(row)),
+                }
+                .map(|dt| dt.to_string())
+                .unwrap_or_default(),
+            ),
+            _ => Cell::Varchar(
+                arrow::util::display::array_value_to_string(column, row).unwrap_or_default(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Int64Array, TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray};
+
+    use super::*;
+
+    #[test]
+    fn from_json_maps_each_value_kind() {
+        assert_eq!(Cell::from_json(&serde_json::Value::Null), Cell::Null);
+        assert_eq!(Cell::from_json(&serde_json::json!(true)), Cell::Boolean(true));
+        assert_eq!(Cell::from_json(&serde_json::json!(42)), Cell::Int(42));
+        assert_eq!(Cell::from_json(&serde_json::json!(1.5)), Cell::Float(1.5));
+        assert_eq!(Cell::from_json(&serde_json::json!("hi")), Cell::Varchar("hi".into()));
+    }
+
+    #[test]
+    fn from_arrow_reads_null_before_checking_type() {
+        let column = Int64Array::from(vec![None]);
+        assert_eq!(Cell::from_arrow(&column, 0), Cell::Null);
+    }
+
+    #[test]
+    fn from_arrow_reads_int64() {
+        let column = Int64Array::from(vec![7]);
+        assert_eq!(Cell::from_arrow(&column, 0), Cell::Int(7));
+    }
+
+    #[test]
+    fn from_arrow_reads_second_precision_timestamps() {
+        let column: Arc<dyn Array> = Arc::new(TimestampSecondArray::from(vec![0]));
+        let Cell::Timestamp(rendered) = Cell::from_arrow(column.as_ref(), 0) else {
+            panic!("expected Cell::Timestamp");
+        };
+        assert!(!rendered.is_empty());
+    }
+
+    #[test]
+    fn from_arrow_reads_millisecond_precision_timestamps() {
+        let column: Arc<dyn Array> = Arc::new(TimestampMillisecondArray::from(vec![0]));
+        let Cell::Timestamp(rendered) = Cell::from_arrow(column.as_ref(), 0) else {
+            panic!("expected Cell::Timestamp");
+        };
+        assert!(!rendered.is_empty());
+    }
+
+    #[test]
+    fn from_arrow_reads_nanosecond_precision_timestamps() {
+        let column: Arc<dyn Array> = Arc::new(TimestampNanosecondArray::from(vec![0]));
+        let Cell::Timestamp(rendered) = Cell::from_arrow(column.as_ref(), 0) else {
+            panic!("expected Cell::Timestamp");
+        };
+        assert!(!rendered.is_empty());
+    }
+}
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use reqwest::{Client, RequestBuilder};
+
+use crate::error::Result;
+
+const DEFAULT_HOST_SUFFIX: &str = "snowflakecomputing.com";
+
+/// Thin wrapper around the shared `reqwest` client and the account's base
+/// URL, so the rest of the crate doesn't have to format hosts by hand.
+#[derive(Clone)]
+pub struct Connection {
+    client: Client,
+    host: String,
+}
+
+impl Connection {
+    pub fn new(account_identifier: &str, host: Option<String>) -> Result<Self> {
+        let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
+        let host = host.unwrap_or_else(|| format!("{account_identifier}.{DEFAULT_HOST_SUFFIX}"));
+
+        Ok(Self { client, host })
+    }
+
+    pub fn get(&self, path: &str) -> RequestBuilder {
+        self.client.get(format!("https://{}{path}", self.host))
+    }
+
+    pub fn post(&self, path: &str) -> RequestBuilder {
+        self.client.post(format!("https://{}{path}", self.host))
+    }
+
+    /// Access to the underlying client for requests against absolute URLs,
+    /// e.g. presigned chunk download links that live outside the account host.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
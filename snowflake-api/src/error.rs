@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, SnowflakeApiError>;
+
+#[derive(Error, Debug)]
+pub enum SnowflakeApiError {
+    #[error("authentication failed: {0}")]
+    AuthError(String),
+
+    #[error("snowflake returned an error: {0}")]
+    ResponseError(String),
+
+    #[error(transparent)]
+    RequestError(#[from] reqwest::Error),
+
+    #[error(transparent)]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    ArrowError(#[from] arrow::error::ArrowError),
+
+    #[error(transparent)]
+    JwtError(#[from] jsonwebtoken::errors::Error),
+
+    #[error(transparent)]
+    Base64Error(#[from] base64::DecodeError),
+
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
@@ -0,0 +1,635 @@
+//! A minimal async client for Snowflake's SQL API.
+
+pub mod error;
+pub mod requests;
+pub mod responses;
+
+mod auth;
+mod binding;
+mod cell;
+mod connection;
+mod partition;
+mod prepared;
+mod session;
+
+use std::collections::BTreeMap;
+
+use arrow::record_batch::RecordBatch;
+use base64::engine::general_purpose::STANDARD as base64;
+use base64::Engine;
+use bytes::Bytes;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+
+pub use binding::{Binding, ToBinding};
+pub use cell::Cell;
+pub use partition::PartitionedQueryResult;
+pub use prepared::PreparedStatement;
+
+use connection::Connection;
+use error::{Result, SnowflakeApiError};
+use requests::ExecRequest;
+use responses::ExecResponse;
+use session::{AuthMethod, Session};
+
+/// Result of a successfully executed statement, decoded into a convenient
+/// in-memory representation.
+#[derive(Debug)]
+pub enum QueryResult {
+    Arrow(Vec<RecordBatch>),
+    /// One JSON object per row, keyed by column name.
+    Json(Vec<serde_json::Value>),
+    Empty,
+}
+
+impl QueryResult {
+    /// Row/column view over the result, regardless of whether the
+    /// underlying response was Arrow- or JSON-encoded.
+    pub fn rows(&self) -> Result<Vec<Vec<Cell>>> {
+        match self {
+            QueryResult::Arrow(batches) => Ok(batches
+                .iter()
+                .flat_map(|batch| {
+                    (0..batch.num_rows())
+                        .map(|row| batch.columns().iter().map(|col| Cell::from_arrow(col, row)).collect())
+                })
+                .collect()),
+            QueryResult::Json(objects) => Ok(objects
+                .iter()
+                .map(|obj| {
+                    obj.as_object()
+                        .into_iter()
+                        .flat_map(|map| map.values())
+                        .map(Cell::from_json)
+                        .collect()
+                })
+                .collect()),
+            QueryResult::Empty => Ok(vec![]),
+        }
+    }
+
+    /// Same data as [`Self::rows`], but as one JSON object per row keyed by
+    /// column name — handy when you just want `serde_json::Value`s without
+    /// matching on [`Cell`].
+    pub fn json_objects(&self) -> Result<Vec<serde_json::Value>> {
+        match self {
+            QueryResult::Json(objects) => Ok(objects.clone()),
+            QueryResult::Arrow(batches) => {
+                let mut objects = Vec::new();
+                for batch in batches {
+                    let schema = batch.schema();
+                    for row in 0..batch.num_rows() {
+                        let mut map = serde_json::Map::new();
+                        for (i, field) in schema.fields().iter().enumerate() {
+                            let cell = Cell::from_arrow(batch.column(i), row);
+                            map.insert(field.name().clone(), cell.into());
+                        }
+                        objects.push(serde_json::Value::Object(map));
+                    }
+                }
+                Ok(objects)
+            }
+            QueryResult::Empty => Ok(vec![]),
+        }
+    }
+}
+
+/// Result of [`SnowflakeApi::exec_raw`] (or an intermediate stage of
+/// [`SnowflakeApi::parse_arrow_raw_response`]) before it has been decoded
+/// into a [`QueryResult`].
+pub enum RawQueryResult {
+    Bytes(Bytes),
+    Stream(BoxStream<'static, reqwest::Result<Bytes>>),
+}
+
+impl RawQueryResult {
+    /// Decodes the raw Arrow IPC stream into [`QueryResult::Arrow`]. Only
+    /// valid on the `Bytes` variant — callers that asked for a streaming
+    /// result must buffer it first.
+    pub fn deserialize_arrow(self) -> Result<QueryResult> {
+        let bytes = match self {
+            RawQueryResult::Bytes(b) => b,
+            RawQueryResult::Stream(_) => {
+                return Err(SnowflakeApiError::ResponseError(
+                    "cannot decode a streaming result without collecting it first".into(),
+                ))
+            }
+        };
+
+        let reader = arrow::ipc::reader::StreamReader::try_new(bytes.as_ref(), None)?;
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(QueryResult::Arrow(batches))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ResultFormat {
+    Arrow,
+    Json,
+}
+
+impl ResultFormat {
+    fn as_param(self) -> &'static str {
+        match self {
+            ResultFormat::Arrow => "arrow",
+            ResultFormat::Json => "json",
+        }
+    }
+}
+
+enum AuthConfig {
+    Certificate { private_key_pem: String, passphrase: Option<String> },
+    Password { password: String },
+}
+
+struct PendingAuth {
+    account_identifier: String,
+    host: Option<String>,
+    warehouse: Option<String>,
+    database: Option<String>,
+    schema: Option<String>,
+    username: String,
+    role: Option<String>,
+    auth: AuthConfig,
+}
+
+enum SessionState {
+    Pending(Box<PendingAuth>),
+    Active(Session),
+}
+
+/// Entry point for authenticating with Snowflake and executing SQL.
+///
+/// Construction (`with_certificate_auth`/`with_password_auth`) is
+/// synchronous; the actual login round-trip happens lazily on the first
+/// call that needs a session.
+pub struct SnowflakeApi {
+    state: SessionState,
+}
+
+impl SnowflakeApi {
+    /// `private_key_pem` accepts a plaintext PKCS#8 (`BEGIN PRIVATE KEY`) or
+    /// PKCS#1 (`BEGIN RSA PRIVATE KEY`) PEM, or a plaintext OpenSSH-format
+    /// key. For a passphrase-protected key, use
+    /// [`Self::with_certificate_auth_encrypted`] instead.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_certificate_auth(
+        account_identifier: &str,
+        warehouse: Option<&str>,
+        database: Option<&str>,
+        schema: Option<&str>,
+        username: &str,
+        role: Option<&str>,
+        private_key_pem: &str,
+    ) -> Result<Self> {
+        Self::new(
+            account_identifier,
+            warehouse,
+            database,
+            schema,
+            username,
+            role,
+            AuthConfig::Certificate {
+                private_key_pem: private_key_pem.to_string(),
+                passphrase: None,
+            },
+        )
+    }
+
+    /// Like [`Self::with_certificate_auth`], but for a private key that is
+    /// passphrase-protected (PKCS#8 encryption or an encrypted OpenSSH key).
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_certificate_auth_encrypted(
+        account_identifier: &str,
+        warehouse: Option<&str>,
+        database: Option<&str>,
+        schema: Option<&str>,
+        username: &str,
+        role: Option<&str>,
+        private_key_pem: &str,
+        passphrase: &str,
+    ) -> Result<Self> {
+        Self::new(
+            account_identifier,
+            warehouse,
+            database,
+            schema,
+            username,
+            role,
+            AuthConfig::Certificate {
+                private_key_pem: private_key_pem.to_string(),
+                passphrase: Some(passphrase.to_string()),
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_password_auth(
+        account_identifier: &str,
+        warehouse: Option<&str>,
+        database: Option<&str>,
+        schema: Option<&str>,
+        username: &str,
+        role: Option<&str>,
+        password: &str,
+    ) -> Result<Self> {
+        Self::new(
+            account_identifier,
+            warehouse,
+            database,
+            schema,
+            username,
+            role,
+            AuthConfig::Password {
+                password: password.to_string(),
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        account_identifier: &str,
+        warehouse: Option<&str>,
+        database: Option<&str>,
+        schema: Option<&str>,
+        username: &str,
+        role: Option<&str>,
+        auth: AuthConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            state: SessionState::Pending(Box::new(PendingAuth {
+                account_identifier: account_identifier.to_string(),
+                host: None,
+                warehouse: warehouse.map(String::from),
+                database: database.map(String::from),
+                schema: schema.map(String::from),
+                username: username.to_string(),
+                role: role.map(String::from),
+                auth,
+            })),
+        })
+    }
+
+    /// Overrides the host derived from the account identifier, e.g. for
+    /// private-link endpoints. Must be called before the first query.
+    pub fn with_host(mut self, host: Option<String>) -> Self {
+        if let (Some(host), SessionState::Pending(pending)) = (host, &mut self.state) {
+            pending.host = Some(host);
+        }
+        self
+    }
+
+    /// Builds a parameterized statement. See [`PreparedStatement`].
+    pub fn prepare(&mut self, sql_text: impl Into<String>) -> PreparedStatement<'_> {
+        PreparedStatement::new(self, sql_text)
+    }
+
+    pub async fn exec(&mut self, sql: &str) -> Result<QueryResult> {
+        let resp = self.exec_response_with_format(sql, ResultFormat::Arrow, BTreeMap::new()).await?;
+        self.response_to_query_result(resp).await
+    }
+
+    pub async fn exec_json(&mut self, sql: &str) -> Result<serde_json::Value> {
+        let resp = self.exec_response_with_format(sql, ResultFormat::Json, BTreeMap::new()).await?;
+        match self.response_to_query_result(resp).await? {
+            QueryResult::Json(rows) => Ok(serde_json::Value::Array(rows)),
+            _ => Ok(serde_json::Value::Array(vec![])),
+        }
+    }
+
+    pub async fn exec_response(&mut self, sql: &str) -> Result<ExecResponse> {
+        self.exec_response_with_format(sql, ResultFormat::Arrow, BTreeMap::new()).await
+    }
+
+    /// Executes `sql` and returns the raw HTTP response body, either
+    /// buffered or as a byte stream, without parsing it as JSON.
+    pub async fn exec_raw(&mut self, sql: &str, streaming: bool) -> Result<RawQueryResult> {
+        let request = ExecRequest::new(sql, ResultFormat::Arrow.as_param(), BTreeMap::new());
+        let session = self.ensure_session().await?;
+
+        let response = session
+            .connection()
+            .post("/queries/v1/query-request")
+            .header("Authorization", format!("Snowflake Token=\"{}\"", session.token()))
+            .json(&request)
+            .send()
+            .await?;
+
+        if streaming {
+            Ok(RawQueryResult::Stream(response.bytes_stream().boxed()))
+        } else {
+            Ok(RawQueryResult::Bytes(response.bytes().await?))
+        }
+    }
+
+    /// Executes `sql` and returns a handle over its result partitions,
+    /// fetching and decoding each one lazily as the caller iterates — use
+    /// this instead of [`Self::exec`] for gigabyte-scale results that
+    /// shouldn't be buffered in memory all at once.
+    pub async fn exec_streamed(&mut self, sql: &str) -> Result<PartitionedQueryResult> {
+        let resp = self.exec_response_with_format(sql, ResultFormat::Arrow, BTreeMap::new()).await?;
+        let split = partition::split_partitions(resp)?;
+
+        let session = self.ensure_session().await?;
+        Ok(PartitionedQueryResult::new(
+            session.connection().clone(),
+            split.qrmk,
+            split.chunk_headers,
+            split.first_partition,
+            split.chunks,
+        ))
+    }
+
+    /// Submits all `statements` as a single multi-statement request,
+    /// avoiding the round-trip cost of issuing one `exec` per statement and
+    /// giving atomic ordering for setup-then-query workflows (e.g.
+    /// `USE WAREHOUSE ...; ALTER SESSION ...; SELECT ...`).
+    pub async fn exec_batch(&mut self, statements: &[&str]) -> Result<Vec<QueryResult>> {
+        let combined = statements.join(";\n");
+        let resp = self.exec_response_multi(&combined, statements.len()).await?;
+
+        let Some(data) = &resp.data else {
+            return Ok(vec![]);
+        };
+
+        match data.result_ids.clone() {
+            Some(ids) => {
+                let mut results = Vec::with_capacity(statements.len());
+                for query_id in ids.split(',').filter(|s| !s.is_empty()) {
+                    results.push(self.fetch_statement_result(query_id).await?);
+                }
+                Ok(results)
+            }
+            // Snowflake only sets `resultIds` when the request actually ran
+            // as multiple statements; a single-statement batch comes back
+            // as one plain result. For more than one statement, a missing
+            // `resultIds` means we can't honor the one-result-per-statement
+            // contract, so surface that instead of silently returning one.
+            None if statements.len() == 1 => Ok(vec![self.response_to_query_result(resp).await?]),
+            None => Err(SnowflakeApiError::ResponseError(format!(
+                "expected {} result(s) for multi-statement batch, but response had no resultIds",
+                statements.len()
+            ))),
+        }
+    }
+
+    async fn exec_response_multi(&mut self, sql: &str, statement_count: usize) -> Result<ExecResponse> {
+        let request = ExecRequest::new(sql, ResultFormat::Arrow.as_param(), BTreeMap::new())
+            .with_statement_count(statement_count);
+        let session = self.ensure_session().await?;
+
+        let response: ExecResponse = session
+            .connection()
+            .post("/queries/v1/query-request")
+            .header("Authorization", format!("Snowflake Token=\"{}\"", session.token()))
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if !response.success {
+            return Err(SnowflakeApiError::ResponseError(
+                response.message.clone().unwrap_or_else(|| "query failed".into()),
+            ));
+        }
+
+        Ok(response)
+    }
+
+    async fn fetch_statement_result(&mut self, query_id: &str) -> Result<QueryResult> {
+        let session = self.ensure_session().await?;
+
+        let response: ExecResponse = session
+            .connection()
+            .get(&format!("/queries/{query_id}/result"))
+            .header("Authorization", format!("Snowflake Token=\"{}\"", session.token()))
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        self.response_to_query_result(response).await
+    }
+
+    pub(crate) async fn exec_with_bindings(
+        &mut self,
+        sql: &str,
+        bindings: BTreeMap<String, Binding>,
+    ) -> Result<QueryResult> {
+        let resp = self.exec_response_with_format(sql, ResultFormat::Arrow, bindings).await?;
+        self.response_to_query_result(resp).await
+    }
+
+    async fn exec_response_with_format(
+        &mut self,
+        sql: &str,
+        format: ResultFormat,
+        bindings: BTreeMap<String, Binding>,
+    ) -> Result<ExecResponse> {
+        let request = ExecRequest::new(sql, format.as_param(), bindings);
+        let session = self.ensure_session().await?;
+
+        let response: ExecResponse = session
+            .connection()
+            .post("/queries/v1/query-request")
+            .header("Authorization", format!("Snowflake Token=\"{}\"", session.token()))
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if !response.success {
+            return Err(SnowflakeApiError::ResponseError(
+                response.message.clone().unwrap_or_else(|| "query failed".into()),
+            ));
+        }
+
+        Ok(response)
+    }
+
+    async fn response_to_query_result(&mut self, resp: ExecResponse) -> Result<QueryResult> {
+        let Some(data) = &resp.data else {
+            return Ok(QueryResult::Empty);
+        };
+
+        match data.query_result_format.as_deref() {
+            Some("arrow") => {
+                let split = partition::split_partitions(resp)?;
+                let connection = self.ensure_session().await?.connection().clone();
+                let partitions = PartitionedQueryResult::new(
+                    connection,
+                    split.qrmk,
+                    split.chunk_headers,
+                    split.first_partition,
+                    split.chunks,
+                )
+                .partitions();
+                let partitions: Vec<QueryResult> = futures_util::TryStreamExt::try_collect(partitions).await?;
+                let batches = partitions
+                    .into_iter()
+                    .flat_map(|p| if let QueryResult::Arrow(b) = p { b } else { vec![] })
+                    .collect();
+                Ok(QueryResult::Arrow(batches))
+            }
+            _ => Ok(QueryResult::Json(rows_to_json_objects(data))),
+        }
+    }
+
+    /// Extracts the raw Arrow IPC bytes embedded in a query response,
+    /// without decoding them into `RecordBatch`es yet.
+    pub async fn parse_arrow_raw_response(&self, resp: ExecResponse) -> Result<RawQueryResult> {
+        let data = resp
+            .data
+            .ok_or_else(|| SnowflakeApiError::ResponseError("response had no data".into()))?;
+
+        let bytes = base64.decode(data.rowset_base64.unwrap_or_default())?;
+        Ok(RawQueryResult::Bytes(bytes.into()))
+    }
+
+    async fn ensure_session(&mut self) -> Result<&Session> {
+        if let SessionState::Pending(pending) = &self.state {
+            let connection = Connection::new(&pending.account_identifier, pending.host.clone())?;
+
+            let auth = match &pending.auth {
+                AuthConfig::Certificate { private_key_pem, passphrase } => AuthMethod::Certificate {
+                    jwt: auth::build_jwt(
+                        &pending.account_identifier,
+                        &pending.username,
+                        private_key_pem,
+                        passphrase.as_deref(),
+                    )?,
+                },
+                AuthConfig::Password { password } => AuthMethod::Password { password },
+            };
+
+            let session = Session::login(
+                connection,
+                &pending.account_identifier,
+                pending.warehouse.as_deref(),
+                pending.database.as_deref(),
+                pending.schema.as_deref(),
+                &pending.username,
+                pending.role.as_deref(),
+                auth,
+            )
+            .await?;
+
+            self.state = SessionState::Active(session);
+        }
+
+        match &self.state {
+            SessionState::Active(session) => Ok(session),
+            SessionState::Pending(_) => unreachable!("session was just established"),
+        }
+    }
+}
+
+/// Snowflake's JSON result format sends every cell as a string; coerce each
+/// one back into a native JSON type using the column's declared type so
+/// `QueryResult::Json` / [`Cell`] don't have to special-case stringly-typed
+/// numbers and booleans.
+fn rows_to_json_objects(data: &responses::ExecResponseData) -> Vec<serde_json::Value> {
+    data.rowset
+        .iter()
+        .map(|row| {
+            let mut map = serde_json::Map::new();
+            for (row_type, value) in data.row_type.iter().zip(row.iter()) {
+                map.insert(row_type.name.clone(), coerce_json_cell(row_type, value));
+            }
+            serde_json::Value::Object(map)
+        })
+        .collect()
+}
+
+fn coerce_json_cell(row_type: &responses::RowType, value: &serde_json::Value) -> serde_json::Value {
+    let Some(raw) = value.as_str() else {
+        return value.clone();
+    };
+
+    match row_type.type_name.as_str() {
+        "fixed" => raw.parse::<i64>().map(Into::into).unwrap_or_else(|_| value.clone()),
+        "real" => raw.parse::<f64>().ok().and_then(serde_json::Number::from_f64).map(serde_json::Value::Number).unwrap_or_else(|| value.clone()),
+        "boolean" => serde_json::Value::Bool(raw == "1" || raw.eq_ignore_ascii_case("true")),
+        _ => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use responses::RowType;
+
+    fn row_type(name: &str, type_name: &str) -> RowType {
+        RowType { name: name.into(), type_name: type_name.into(), nullable: true }
+    }
+
+    #[test]
+    fn coerce_json_cell_parses_fixed_and_real_and_boolean() {
+        let fixed = row_type("id", "fixed");
+        assert_eq!(coerce_json_cell(&fixed, &serde_json::json!("42")), serde_json::json!(42));
+
+        let real = row_type("amount", "real");
+        assert_eq!(coerce_json_cell(&real, &serde_json::json!("1.5")), serde_json::json!(1.5));
+
+        let boolean = row_type("flag", "boolean");
+        assert_eq!(coerce_json_cell(&boolean, &serde_json::json!("1")), serde_json::json!(true));
+        assert_eq!(coerce_json_cell(&boolean, &serde_json::json!("0")), serde_json::json!(false));
+    }
+
+    #[test]
+    fn coerce_json_cell_passes_through_non_string_and_unknown_types() {
+        let text = row_type("name", "text");
+        assert_eq!(coerce_json_cell(&text, &serde_json::json!("hi")), serde_json::json!("hi"));
+        assert_eq!(coerce_json_cell(&text, &serde_json::Value::Null), serde_json::Value::Null);
+    }
+
+    #[test]
+    fn rows_to_json_objects_preserves_declared_column_order() {
+        let data = responses::ExecResponseData {
+            row_type: vec![row_type("z_col", "text"), row_type("a_col", "fixed")],
+            rowset_base64: None,
+            rowset: vec![vec![serde_json::json!("hi"), serde_json::json!("3")]],
+            total: None,
+            chunks: vec![],
+            qrmk: None,
+            chunk_headers: Default::default(),
+            query_result_format: None,
+            query_id: None,
+            result_ids: None,
+        };
+
+        let objects = rows_to_json_objects(&data);
+        let names: Vec<&str> = objects[0].as_object().unwrap().keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["z_col", "a_col"]);
+    }
+
+    #[test]
+    fn json_objects_on_arrow_data_returns_bare_values() {
+        use arrow::array::{Int64Array, StringArray};
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![Some(7), None])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )
+        .unwrap();
+
+        let result = QueryResult::Arrow(vec![batch]);
+        let objects = result.json_objects().unwrap();
+
+        assert_eq!(objects[0], serde_json::json!({"id": 7, "name": "a"}));
+        assert_eq!(objects[1], serde_json::json!({"id": null, "name": "b"}));
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+use futures_util::stream::{self, Stream};
+
+use crate::connection::Connection;
+use crate::error::Result;
+use crate::responses::{ChunkInfo, ExecResponse};
+use crate::{QueryResult, RawQueryResult};
+
+/// A query result too large to fit in a single response, split across
+/// partitions that are fetched lazily as the caller consumes them.
+pub struct PartitionedQueryResult {
+    connection: Connection,
+    qrmk: Option<String>,
+    chunk_headers: HashMap<String, String>,
+    first_partition: Option<RawQueryResult>,
+    chunks: Vec<ChunkInfo>,
+}
+
+impl PartitionedQueryResult {
+    pub(crate) fn new(
+        connection: Connection,
+        qrmk: Option<String>,
+        chunk_headers: HashMap<String, String>,
+        first_partition: RawQueryResult,
+        chunks: Vec<ChunkInfo>,
+    ) -> Self {
+        Self {
+            connection,
+            qrmk,
+            chunk_headers,
+            first_partition: Some(first_partition),
+            chunks,
+        }
+    }
+
+    /// Total number of partitions, including the one embedded in the
+    /// initial statement response.
+    pub fn partition_count(&self) -> usize {
+        self.chunks.len() + self.first_partition.is_some() as usize
+    }
+
+    /// Lazily fetches and decodes each partition's Arrow batch on demand,
+    /// so gigabyte-scale results don't have to be buffered up front.
+    pub fn partitions(self) -> impl Stream<Item = Result<QueryResult>> {
+        stream::unfold(self, |mut state| async move {
+            if let Some(first) = state.first_partition.take() {
+                return Some((first.deserialize_arrow(), state));
+            }
+
+            if state.chunks.is_empty() {
+                return None;
+            }
+
+            let chunk = state.chunks.remove(0);
+            let result = fetch_partition(&state.connection, state.qrmk.as_deref(), &state.chunk_headers, &chunk).await;
+            Some((result, state))
+        })
+    }
+}
+
+/// Fetches a chunk from off-host blob storage. Chunk URLs are presigned and
+/// must NOT carry the Snowflake session token; encrypted chunks instead rely
+/// on the `chunkHeaders` (and `qrmk` decryption key) returned alongside the
+/// query response.
+async fn fetch_partition(
+    connection: &Connection,
+    qrmk: Option<&str>,
+    chunk_headers: &HashMap<String, String>,
+    chunk: &ChunkInfo,
+) -> Result<QueryResult> {
+    let mut request = connection.client().get(&chunk.url);
+
+    for (name, value) in chunk_headers {
+        request = request.header(name, value);
+    }
+
+    if let Some(qrmk) = qrmk {
+        request = request
+            .header("x-amz-server-side-encryption-customer-key", qrmk)
+            .header("x-amz-server-side-encryption-customer-algorithm", "AES256");
+    }
+
+    let bytes = request.send().await?.bytes().await?;
+
+    RawQueryResult::Bytes(bytes).deserialize_arrow()
+}
+
+/// Everything needed to fetch and decrypt the partitions of a completed
+/// query response: the first partition plus the remaining chunk pointers,
+/// the chunk decryption key, and any per-chunk headers required to fetch
+/// them from blob storage.
+pub(crate) struct SplitPartitions {
+    pub first_partition: RawQueryResult,
+    pub chunks: Vec<ChunkInfo>,
+    pub qrmk: Option<String>,
+    pub chunk_headers: HashMap<String, String>,
+}
+
+pub(crate) fn split_partitions(resp: ExecResponse) -> Result<SplitPartitions> {
+    let data = resp
+        .data
+        .ok_or_else(|| crate::error::SnowflakeApiError::ResponseError("response had no data".into()))?;
+
+    let bytes = base64::engine::general_purpose::STANDARD.decode(data.rowset_base64.unwrap_or_default())?;
+
+    Ok(SplitPartitions {
+        first_partition: RawQueryResult::Bytes(bytes.into()),
+        chunks: data.chunks,
+        qrmk: data.qrmk,
+        chunk_headers: data.chunk_headers,
+    })
+}
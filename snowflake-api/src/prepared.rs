@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+
+use crate::binding::{Binding, ToBinding};
+use crate::error::Result;
+use crate::{QueryResult, SnowflakeApi};
+
+/// Builds a parameterized SQL statement, collecting positional bindings
+/// before handing off to [`SnowflakeApi`]'s normal exec path.
+///
+/// ```no_run
+/// # use snowflake_api::SnowflakeApi;
+/// # async fn run(api: &mut SnowflakeApi) -> anyhow::Result<()> {
+/// let result = api
+///     .prepare("SELECT * FROM t WHERE id = ? AND name = ?")
+///     .add_binding(10)
+///     .add_binding("Henry")
+///     .exec()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PreparedStatement<'a> {
+    api: &'a mut SnowflakeApi,
+    sql_text: String,
+    bindings: BTreeMap<String, Binding>,
+}
+
+impl<'a> PreparedStatement<'a> {
+    pub(crate) fn new(api: &'a mut SnowflakeApi, sql_text: impl Into<String>) -> Self {
+        Self {
+            api,
+            sql_text: sql_text.into(),
+            bindings: BTreeMap::new(),
+        }
+    }
+
+    /// Appends the next `?` placeholder's value, in order. Bindings are
+    /// 1-indexed, per Snowflake's SQL API.
+    pub fn add_binding(mut self, value: impl ToBinding) -> Self {
+        let index = (self.bindings.len() + 1).to_string();
+        self.bindings.insert(index, value.to_binding());
+        self
+    }
+
+    pub async fn exec(self) -> Result<QueryResult> {
+        self.api.exec_with_bindings(&self.sql_text, self.bindings).await
+    }
+}
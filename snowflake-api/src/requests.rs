@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::binding::Binding;
+use crate::session::AuthMethod;
+
+#[derive(Serialize)]
+pub struct LoginRequest {
+    data: LoginData,
+}
+
+#[derive(Serialize)]
+struct LoginData {
+    #[serde(rename = "ACCOUNT_NAME")]
+    account_name: String,
+    #[serde(rename = "LOGIN_NAME")]
+    login_name: String,
+    #[serde(rename = "CLIENT_APP_ID")]
+    client_app_id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(rename = "AUTHENTICATOR", skip_serializing_if = "Option::is_none")]
+    authenticator: Option<&'static str>,
+    #[serde(rename = "TOKEN", skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+    #[serde(rename = "SESSION_PARAMETERS")]
+    session_parameters: serde_json::Value,
+}
+
+impl LoginRequest {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_identifier: &str,
+        warehouse: Option<&str>,
+        database: Option<&str>,
+        schema: Option<&str>,
+        username: &str,
+        role: Option<&str>,
+        auth: &AuthMethod,
+    ) -> Self {
+        let (password, authenticator, token) = match auth {
+            AuthMethod::Password { password } => (Some(password.to_string()), None, None),
+            AuthMethod::Certificate { jwt } => (None, Some("SNOWFLAKE_JWT"), Some(jwt.clone())),
+        };
+
+        Self {
+            data: LoginData {
+                account_name: account_identifier.to_string(),
+                login_name: username.to_string(),
+                client_app_id: "SnowflakeApiRs",
+                password,
+                authenticator,
+                token,
+                session_parameters: json!({
+                    "WAREHOUSE": warehouse,
+                    "DATABASE": database,
+                    "SCHEMA": schema,
+                    "ROLE": role,
+                }),
+            },
+        }
+    }
+}
+
+/// Body sent to `/queries/v1/query-request`.
+#[derive(Serialize)]
+pub struct ExecRequest {
+    #[serde(rename = "sqlText")]
+    sql_text: String,
+    #[serde(rename = "asyncExec")]
+    async_exec: bool,
+    parameters: serde_json::Value,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    bindings: BTreeMap<String, Binding>,
+}
+
+impl ExecRequest {
+    pub fn new(sql_text: impl Into<String>, result_format: &str, bindings: BTreeMap<String, Binding>) -> Self {
+        Self {
+            sql_text: sql_text.into(),
+            async_exec: false,
+            parameters: json!({ "QUERY_RESULT_FORMAT": result_format }),
+            bindings,
+        }
+    }
+
+    /// Tells Snowflake to expect `count` `;`-separated statements in
+    /// `sqlText`, per its multi-statement support.
+    pub fn with_statement_count(mut self, count: usize) -> Self {
+        self.parameters["MULTI_STATEMENT_COUNT"] = json!(count);
+        self
+    }
+}
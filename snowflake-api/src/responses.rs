@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Envelope returned by Snowflake's `/queries/v1/query-request` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecResponse {
+    pub success: bool,
+    pub message: Option<String>,
+    pub data: Option<ExecResponseData>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecResponseData {
+    #[serde(rename = "rowtype", default)]
+    pub row_type: Vec<RowType>,
+    #[serde(rename = "rowsetBase64")]
+    pub rowset_base64: Option<String>,
+    #[serde(default)]
+    pub rowset: Vec<Vec<serde_json::Value>>,
+    pub total: Option<i64>,
+    #[serde(default)]
+    pub chunks: Vec<ChunkInfo>,
+    pub qrmk: Option<String>,
+    #[serde(rename = "chunkHeaders", default)]
+    pub chunk_headers: HashMap<String, String>,
+    #[serde(rename = "queryResultFormat")]
+    pub query_result_format: Option<String>,
+    #[serde(rename = "queryId")]
+    pub query_id: Option<String>,
+    /// Comma-separated query IDs of each statement's result, present when
+    /// the request ran with `MULTI_STATEMENT_COUNT` set.
+    #[serde(rename = "resultIds")]
+    pub result_ids: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RowType {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub nullable: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChunkInfo {
+    pub url: String,
+    #[serde(rename = "rowCount")]
+    pub row_count: i64,
+    #[serde(rename = "uncompressedSize")]
+    pub uncompressed_size: i64,
+}
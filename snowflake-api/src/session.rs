@@ -0,0 +1,76 @@
+use serde::Deserialize;
+
+use crate::connection::Connection;
+use crate::error::{Result, SnowflakeApiError};
+use crate::requests::LoginRequest;
+
+#[derive(Deserialize)]
+struct LoginResponseData {
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct LoginResponse {
+    success: bool,
+    message: Option<String>,
+    data: Option<LoginResponseData>,
+}
+
+/// The method used to authenticate a [`Session`].
+pub enum AuthMethod<'a> {
+    Certificate { jwt: String },
+    Password { password: &'a str },
+}
+
+/// Holds the session token returned by Snowflake after a successful login,
+/// for callers to send in the `Authorization` header of subsequent SQL API
+/// requests.
+pub struct Session {
+    connection: Connection,
+    token: String,
+}
+
+impl Session {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn login(
+        connection: Connection,
+        account_identifier: &str,
+        warehouse: Option<&str>,
+        database: Option<&str>,
+        schema: Option<&str>,
+        username: &str,
+        role: Option<&str>,
+        auth: AuthMethod<'_>,
+    ) -> Result<Self> {
+        let request = LoginRequest::new(account_identifier, warehouse, database, schema, username, role, &auth);
+
+        let response: LoginResponse = connection
+            .post("/session/v1/login-request")
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if !response.success {
+            return Err(SnowflakeApiError::AuthError(
+                response.message.unwrap_or_else(|| "login failed".into()),
+            ));
+        }
+
+        let token = response
+            .data
+            .ok_or_else(|| SnowflakeApiError::AuthError("login response had no session data".into()))?
+            .token;
+
+        Ok(Self { connection, token })
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+}